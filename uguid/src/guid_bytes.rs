@@ -0,0 +1,206 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Guid;
+use core::fmt::{self, Display, Formatter};
+
+/// Error returned by `TryFrom<&[u8]>` for [`Guid`] when the slice is
+/// not exactly 16 bytes long.
+///
+/// This is distinct from [`GuidFromStrError`], which covers textual
+/// parsing, since a byte-slice conversion has no notion of a string
+/// format to report.
+///
+/// [`GuidFromStrError`]: crate::GuidFromStrError
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub struct GuidFromSliceError {
+    /// The length of the rejected slice, in bytes.
+    pub actual: usize,
+}
+
+impl Display for GuidFromSliceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GUID byte slice has invalid length {}, expected 16",
+            self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GuidFromSliceError {}
+
+impl Guid {
+    /// Get the 16-byte on-disk representation of the GUID.
+    ///
+    /// This is the mixed-endian layout used by GPT headers and
+    /// partition entries: `time_low`, `time_mid`, and
+    /// `time_high_and_version` are little-endian, while
+    /// `clock_seq_high_and_reserved`, `clock_seq_low`, and `node` are
+    /// stored in the order given. Use [`Self::to_bytes_be`] for the
+    /// fully big-endian "RFC 4122 network order" form used by some
+    /// interchange formats instead.
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        [
+            self.time_low[0],
+            self.time_low[1],
+            self.time_low[2],
+            self.time_low[3],
+            self.time_mid[0],
+            self.time_mid[1],
+            self.time_high_and_version[0],
+            self.time_high_and_version[1],
+            self.clock_seq_high_and_reserved,
+            self.clock_seq_low,
+            self.node[0],
+            self.node[1],
+            self.node[2],
+            self.node[3],
+            self.node[4],
+            self.node[5],
+        ]
+    }
+
+    /// Create a `Guid` from its 16-byte on-disk representation.
+    ///
+    /// This expects the same mixed-endian layout produced by
+    /// [`Self::to_bytes`]. Use [`Self::from_bytes_be`] for the fully
+    /// big-endian "RFC 4122 network order" form instead.
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self {
+            time_low: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            time_mid: [bytes[4], bytes[5]],
+            time_high_and_version: [bytes[6], bytes[7]],
+            clock_seq_high_and_reserved: bytes[8],
+            clock_seq_low: bytes[9],
+            node: [
+                bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        }
+    }
+
+    /// Get the 16-byte big-endian "RFC 4122 network order"
+    /// representation of the GUID.
+    ///
+    /// Unlike [`Self::to_bytes`], `time_low`, `time_mid`, and
+    /// `time_high_and_version` are byte-swapped to big-endian here.
+    /// This is the representation used by some interchange formats; GPT
+    /// headers on disk use [`Self::to_bytes`] instead.
+    #[must_use]
+    pub const fn to_bytes_be(&self) -> [u8; 16] {
+        [
+            self.time_low[3],
+            self.time_low[2],
+            self.time_low[1],
+            self.time_low[0],
+            self.time_mid[1],
+            self.time_mid[0],
+            self.time_high_and_version[1],
+            self.time_high_and_version[0],
+            self.clock_seq_high_and_reserved,
+            self.clock_seq_low,
+            self.node[0],
+            self.node[1],
+            self.node[2],
+            self.node[3],
+            self.node[4],
+            self.node[5],
+        ]
+    }
+
+    /// Create a `Guid` from its 16-byte big-endian "RFC 4122 network
+    /// order" representation.
+    ///
+    /// This is the inverse of [`Self::to_bytes_be`]; use
+    /// [`Self::from_bytes`] for the mixed-endian on-disk GPT layout
+    /// instead.
+    #[must_use]
+    pub const fn from_bytes_be(bytes: [u8; 16]) -> Self {
+        Self {
+            time_low: [bytes[3], bytes[2], bytes[1], bytes[0]],
+            time_mid: [bytes[5], bytes[4]],
+            time_high_and_version: [bytes[7], bytes[6]],
+            clock_seq_high_and_reserved: bytes[8],
+            clock_seq_low: bytes[9],
+            node: [
+                bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Guid {
+    type Error = GuidFromSliceError;
+
+    /// Construct a `Guid` from a slice in the mixed-endian on-disk
+    /// layout used by [`Guid::to_bytes`]/[`Guid::from_bytes`].
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| {
+            GuidFromSliceError { actual: bytes.len() }
+        })?;
+        Ok(Self::from_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_from_bytes_roundtrip() {
+        let guid = Guid::EFI_SYSTEM_PARTITION;
+        assert_eq!(Guid::from_bytes(guid.to_bytes()), guid);
+    }
+
+    #[test]
+    fn test_to_from_bytes_be_roundtrip() {
+        let guid = Guid::EFI_SYSTEM_PARTITION;
+        assert_eq!(Guid::from_bytes_be(guid.to_bytes_be()), guid);
+    }
+
+    #[test]
+    fn test_to_bytes_be_matches_rfc4122() {
+        // C12A7328-F81F-11D2-BA4B-00A0C93EC93B in RFC 4122 network
+        // order is simply the hex digits in order, with no byte
+        // swapping.
+        assert_eq!(
+            Guid::EFI_SYSTEM_PARTITION.to_bytes_be(),
+            [
+                0xC1, 0x2A, 0x73, 0x28, 0xF8, 0x1F, 0x11, 0xD2, 0xBA, 0x4B,
+                0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let guid = Guid::EFI_SYSTEM_PARTITION;
+        let bytes = guid.to_bytes();
+        assert_eq!(Guid::try_from(bytes.as_slice()), Ok(guid));
+    }
+
+    #[test]
+    fn test_try_from_slice_wrong_length() {
+        assert_eq!(
+            Guid::try_from([0u8; 15].as_slice()),
+            Err(GuidFromSliceError { actual: 15 })
+        );
+    }
+}
@@ -22,17 +22,59 @@ use core::fmt::{self, Display, Formatter};
 ///
 /// [`Error`]: std::error::Error
 /// [`Guid::from_str`]: core::str::FromStr::from_str
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct GuidFromStrError;
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum GuidFromStrError {
+    /// The input string is not one of the accepted lengths (36 bytes
+    /// hyphenated, 38 bytes braced, or 32 bytes with no separators), or
+    /// is 38 bytes long but not enclosed in `{` and `}`.
+    WrongLength {
+        /// The length of the rejected input, in bytes.
+        actual: usize,
+    },
+
+    /// A hyphen separator was expected at `index` but was not found
+    /// there.
+    MissingSeparator {
+        /// Byte offset within the input where a `-` was expected.
+        index: usize,
+    },
+
+    /// A byte at `index` is not an ASCII hex digit.
+    InvalidHexChar {
+        /// Byte offset within the input of the invalid character.
+        index: usize,
+        /// The invalid byte itself.
+        byte: u8,
+    },
+}
 
 impl Display for GuidFromStrError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.write_str("GUID hex string does not match expected format \"xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx\"")
+        match self {
+            Self::WrongLength { actual } => write!(
+                f,
+                "GUID string has invalid length {actual}; the canonical hyphenated form requires 36 bytes (`Guid::try_parse` also accepts 38 for the braced form, or 32 with no separators)"
+            ),
+            Self::MissingSeparator { index } => {
+                write!(f, "expected '-' separator at byte offset {index}")
+            }
+            Self::InvalidHexChar { index, byte } => write!(
+                f,
+                "invalid hex character {byte:#04x} at byte offset {index}"
+            ),
+        }
     }
 }
 
-/// Parse a hexadecimal ASCII character as a `u8`.
-const fn parse_byte_from_ascii_char(c: u8) -> Result<u8, GuidFromStrError> {
+#[cfg(feature = "std")]
+impl std::error::Error for GuidFromStrError {}
+
+/// Parse a hexadecimal ASCII character at `index` as a `u8`.
+const fn parse_byte_from_ascii_char(
+    index: usize,
+    c: u8,
+) -> Result<u8, GuidFromStrError> {
     match c {
         b'0' => Ok(0x0),
         b'1' => Ok(0x1),
@@ -50,7 +92,7 @@ const fn parse_byte_from_ascii_char(c: u8) -> Result<u8, GuidFromStrError> {
         b'd' | b'D' => Ok(0xd),
         b'e' | b'E' => Ok(0xe),
         b'f' | b'F' => Ok(0xf),
-        _ => Err(GuidFromStrError),
+        _ => Err(GuidFromStrError::InvalidHexChar { index, byte: c }),
     }
 }
 
@@ -67,14 +109,16 @@ macro_rules! mtry {
     };
 }
 
-/// Parse a pair of hexadecimal ASCII characters as a `u8`. For example,
-/// `(b'1', b'a')` is parsed as `0x1a`.
+/// Parse a pair of hexadecimal ASCII characters at positions `ia` and
+/// `ib` as a `u8`. For example, `(b'1', b'a')` is parsed as `0x1a`.
 const fn parse_byte_from_ascii_char_pair(
+    ia: usize,
     a: u8,
+    ib: usize,
     b: u8,
 ) -> Result<u8, GuidFromStrError> {
-    let a = mtry!(parse_byte_from_ascii_char(a));
-    let b = mtry!(parse_byte_from_ascii_char(b));
+    let a = mtry!(parse_byte_from_ascii_char(ia, a));
+    let b = mtry!(parse_byte_from_ascii_char(ib, b));
     Ok(a << 4 | b)
 }
 
@@ -84,48 +128,118 @@ const fn parse_byte_from_ascii_str_at(
     s: &[u8],
     start: usize,
 ) -> Result<u8, GuidFromStrError> {
-    parse_byte_from_ascii_char_pair(s[start], s[start + 1])
+    parse_byte_from_ascii_char_pair(start, s[start], start + 1, s[start + 1])
 }
 
-pub(crate) const fn try_parse_guid(s: &str) -> Result<Guid, GuidFromStrError> {
+/// Parse the 16 mixed-endian GUID bytes out of `s`, scanning hex-digit
+/// pairs in ascending string order starting at `base` so that the
+/// first offending position in `s` is always the one reported, instead
+/// of whichever byte pair happens to be built first in the
+/// endian-swapped struct layout.
+///
+/// When `has_sep` is set, a `-` separator is expected in `s` before the
+/// 5th, 7th, 9th, and 11th byte (i.e. the canonical hyphenated
+/// layout); otherwise the 32 hex digits are expected to be contiguous.
+const fn parse_bytes_ascending(
+    s: &[u8],
+    base: usize,
+    has_sep: bool,
+) -> Result<[u8; 16], GuidFromStrError> {
+    let mut bytes = [0u8; 16];
+    let mut byte_idx = 0;
+    let mut pos = base;
+    while byte_idx < 16 {
+        if has_sep
+            && (byte_idx == 4 || byte_idx == 6 || byte_idx == 8 || byte_idx == 10)
+        {
+            if s[pos] != b'-' {
+                return Err(GuidFromStrError::MissingSeparator { index: pos });
+            }
+            pos += 1;
+        }
+        bytes[byte_idx] = mtry!(parse_byte_from_ascii_str_at(s, pos));
+        pos += 2;
+        byte_idx += 1;
+    }
+    Ok(bytes)
+}
+
+/// Assemble a [`Guid`] from 16 bytes in ascending string order, as
+/// produced by [`parse_bytes_ascending`], swapping `time_low`,
+/// `time_mid`, and `time_high_and_version` into the little-endian
+/// layout `Guid` stores them in.
+const fn guid_from_bytes_ascending(b: [u8; 16]) -> Guid {
+    Guid {
+        time_low: [b[3], b[2], b[1], b[0]],
+        time_mid: [b[5], b[4]],
+        time_high_and_version: [b[7], b[6]],
+        clock_seq_high_and_reserved: b[8],
+        clock_seq_low: b[9],
+        node: [b[10], b[11], b[12], b[13], b[14], b[15]],
+    }
+}
+
+/// Parse the mixed-endian fields of a GUID out of a 36-byte hyphenated
+/// hex string starting at `base` within `s`, e.g.
+/// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+const fn parse_hyphenated(
+    s: &[u8],
+    base: usize,
+) -> Result<Guid, GuidFromStrError> {
+    let bytes = mtry!(parse_bytes_ascending(s, base, true));
+    Ok(guid_from_bytes_ascending(bytes))
+}
+
+/// Parse the mixed-endian fields of a GUID out of a 32-byte hex string
+/// with no separators, e.g. `xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx`.
+const fn parse_no_sep(s: &[u8], base: usize) -> Result<Guid, GuidFromStrError> {
+    let bytes = mtry!(parse_bytes_ascending(s, base, false));
+    Ok(guid_from_bytes_ascending(bytes))
+}
+
+/// Parse a GUID string in the canonical hyphenated form, e.g.
+/// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`. Braced and separator-less
+/// forms are rejected; use [`try_parse_guid`] for those.
+pub(crate) const fn try_parse_guid_strict(
+    s: &str,
+) -> Result<Guid, GuidFromStrError> {
     // Treat input as ASCII.
     let s = s.as_bytes();
 
     if s.len() != 36 {
-        return Err(GuidFromStrError);
+        return Err(GuidFromStrError::WrongLength { actual: s.len() });
     }
 
-    let sep = b'-';
-    if s[8] != sep || s[13] != sep || s[18] != sep || s[23] != sep {
-        return Err(GuidFromStrError);
+    parse_hyphenated(s, 0)
+}
+
+/// Parse a GUID string, accepting the canonical hyphenated form as well
+/// as the braced registry form (`{xxxxxxxx-...-xxxxxxxxxxxx}`) and the
+/// separator-less 32-character hex form.
+pub(crate) const fn try_parse_guid(s: &str) -> Result<Guid, GuidFromStrError> {
+    // Treat input as ASCII.
+    let s = s.as_bytes();
+
+    match s.len() {
+        36 => parse_hyphenated(s, 0),
+        38 if s[0] == b'{' && s[37] == b'}' => parse_hyphenated(s, 1),
+        32 => parse_no_sep(s, 0),
+        actual => Err(GuidFromStrError::WrongLength { actual }),
     }
+}
 
-    Ok(Guid {
-        time_low: [
-            mtry!(parse_byte_from_ascii_str_at(s, 6)),
-            mtry!(parse_byte_from_ascii_str_at(s, 4)),
-            mtry!(parse_byte_from_ascii_str_at(s, 2)),
-            mtry!(parse_byte_from_ascii_str_at(s, 0)),
-        ],
-        time_mid: [
-            mtry!(parse_byte_from_ascii_str_at(s, 11)),
-            mtry!(parse_byte_from_ascii_str_at(s, 9)),
-        ],
-        time_high_and_version: [
-            mtry!(parse_byte_from_ascii_str_at(s, 16)),
-            mtry!(parse_byte_from_ascii_str_at(s, 14)),
-        ],
-        clock_seq_high_and_reserved: mtry!(parse_byte_from_ascii_str_at(s, 19)),
-        clock_seq_low: mtry!(parse_byte_from_ascii_str_at(s, 21)),
-        node: [
-            mtry!(parse_byte_from_ascii_str_at(s, 24)),
-            mtry!(parse_byte_from_ascii_str_at(s, 26)),
-            mtry!(parse_byte_from_ascii_str_at(s, 28)),
-            mtry!(parse_byte_from_ascii_str_at(s, 30)),
-            mtry!(parse_byte_from_ascii_str_at(s, 32)),
-            mtry!(parse_byte_from_ascii_str_at(s, 34)),
-        ],
-    })
+impl Guid {
+    /// Parse a GUID string in the canonical hyphenated form.
+    ///
+    /// Unlike [`Guid::from_str`], this rejects the braced registry form
+    /// and the separator-less 32-character hex form accepted by the
+    /// lenient parser, preserving the format this crate originally
+    /// required.
+    ///
+    /// [`Guid::from_str`]: core::str::FromStr::from_str
+    pub const fn try_parse_strict(s: &str) -> Result<Self, GuidFromStrError> {
+        try_parse_guid_strict(s)
+    }
 }
 
 #[cfg(test)]
@@ -134,7 +248,70 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        assert_eq!(parse_byte_from_ascii_char_pair(b'1', b'a'), Ok(0x1a));
-        assert_eq!(parse_byte_from_ascii_char_pair(b'8', b'f'), Ok(0x8f));
+        assert_eq!(
+            parse_byte_from_ascii_char_pair(0, b'1', 1, b'a'),
+            Ok(0x1a)
+        );
+        assert_eq!(
+            parse_byte_from_ascii_char_pair(0, b'8', 1, b'f'),
+            Ok(0x8f)
+        );
+    }
+
+    #[test]
+    fn test_try_parse_guid_braced() {
+        let hyphenated =
+            try_parse_guid("01234567-89ab-cdef-0123-456789abcdef").unwrap();
+        let braced =
+            try_parse_guid("{01234567-89ab-cdef-0123-456789abcdef}").unwrap();
+        assert_eq!(hyphenated, braced);
+    }
+
+    #[test]
+    fn test_try_parse_guid_no_sep() {
+        let hyphenated =
+            try_parse_guid("01234567-89ab-cdef-0123-456789abcdef").unwrap();
+        let no_sep =
+            try_parse_guid("0123456789abcdef0123456789abcdef").unwrap();
+        assert_eq!(hyphenated, no_sep);
+    }
+
+    #[test]
+    fn test_try_parse_strict_rejects_lenient_forms() {
+        assert_eq!(
+            Guid::try_parse_strict("{01234567-89ab-cdef-0123-456789abcdef}"),
+            Err(GuidFromStrError::WrongLength { actual: 38 })
+        );
+        assert_eq!(
+            Guid::try_parse_strict("0123456789abcdef0123456789abcdef"),
+            Err(GuidFromStrError::WrongLength { actual: 32 })
+        );
+    }
+
+    #[test]
+    fn test_missing_separator_reports_index() {
+        assert_eq!(
+            try_parse_guid_strict("01234567_89ab-cdef-0123-456789abcdef"),
+            Err(GuidFromStrError::MissingSeparator { index: 8 })
+        );
+    }
+
+    #[test]
+    fn test_invalid_hex_char_reports_index() {
+        assert_eq!(
+            try_parse_guid_strict("g1234567-89ab-cdef-0123-456789abcdef"),
+            Err(GuidFromStrError::InvalidHexChar { index: 0, byte: b'g' })
+        );
+    }
+
+    #[test]
+    fn test_invalid_hex_char_reports_first_offender() {
+        // Invalid characters at both index 0 and index 7: the error
+        // must point at the leftmost one, not whichever byte pair the
+        // endian-swapped struct layout happens to parse first.
+        assert_eq!(
+            try_parse_guid_strict("g123456g-89ab-cdef-0123-456789abcdef"),
+            Err(GuidFromStrError::InvalidHexChar { index: 0, byte: b'g' })
+        );
     }
 }
@@ -0,0 +1,67 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Parse a GUID string literal at compile time, producing a `Guid`
+/// constant.
+///
+/// The input must be in the canonical hyphenated form (see
+/// [`Guid::try_parse_strict`]). A malformed literal is a compile error
+/// rather than a runtime panic or silent misparse:
+///
+/// ```
+/// use uguid::guid;
+///
+/// const ESP: uguid::Guid =
+///     guid!("C12A7328-F81F-11D2-BA4B-00A0C93EC93B");
+/// ```
+///
+/// [`Guid::try_parse_strict`]: crate::Guid::try_parse_strict
+#[macro_export]
+macro_rules! guid {
+    ($s:expr) => {
+        const {
+            match $crate::Guid::try_parse_strict($s) {
+                Ok(guid) => guid,
+                Err($crate::GuidFromStrError::WrongLength { .. }) => {
+                    panic!("invalid GUID literal: wrong length")
+                }
+                Err($crate::GuidFromStrError::MissingSeparator { .. }) => {
+                    panic!("invalid GUID literal: missing '-' separator")
+                }
+                Err($crate::GuidFromStrError::InvalidHexChar { .. }) => {
+                    panic!("invalid GUID literal: invalid hex character")
+                }
+                // `GuidFromStrError` is `#[non_exhaustive]`: the above
+                // arms are exhaustive today, so this one is only
+                // reachable once a future variant is added. Keep it so
+                // downstream crates (where the enum isn't exhaustive)
+                // still compile, without losing the per-variant
+                // messages above in the meantime.
+                #[allow(unreachable_patterns)]
+                Err(_) => panic!("invalid GUID literal"),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Guid;
+
+    #[test]
+    fn test_guid_macro() {
+        const ESP: Guid = guid!("C12A7328-F81F-11D2-BA4B-00A0C93EC93B");
+        assert_eq!(ESP, Guid::EFI_SYSTEM_PARTITION);
+    }
+}
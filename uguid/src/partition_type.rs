@@ -0,0 +1,300 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Guid;
+use core::fmt::{self, Display, Formatter};
+
+impl Guid {
+    /// GPT partition-type GUID for an unused partition entry.
+    pub const UNUSED: Guid = Guid {
+        time_low: [0x00, 0x00, 0x00, 0x00],
+        time_mid: [0x00, 0x00],
+        time_high_and_version: [0x00, 0x00],
+        clock_seq_high_and_reserved: 0x00,
+        clock_seq_low: 0x00,
+        node: [0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    };
+
+    /// GPT partition-type GUID for an EFI System Partition.
+    pub const EFI_SYSTEM_PARTITION: Guid = Guid {
+        time_low: [0x28, 0x73, 0x2A, 0xC1],
+        time_mid: [0x1F, 0xF8],
+        time_high_and_version: [0xD2, 0x11],
+        clock_seq_high_and_reserved: 0xBA,
+        clock_seq_low: 0x4B,
+        node: [0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B],
+    };
+
+    /// GPT partition-type GUID for a partition holding a legacy MBR.
+    pub const LEGACY_MBR: Guid = Guid {
+        time_low: [0x41, 0xEE, 0x4D, 0x02],
+        time_mid: [0xE7, 0x33],
+        time_high_and_version: [0xD3, 0x11],
+        clock_seq_high_and_reserved: 0x9D,
+        clock_seq_low: 0x69,
+        node: [0x00, 0x08, 0xC7, 0x81, 0xF3, 0x9F],
+    };
+
+    /// GPT partition-type GUID for a Linux filesystem data partition.
+    pub const LINUX_FILESYSTEM_DATA: Guid = Guid {
+        time_low: [0xAF, 0x3D, 0xC6, 0x0F],
+        time_mid: [0x83, 0x84],
+        time_high_and_version: [0x72, 0x47],
+        clock_seq_high_and_reserved: 0x8E,
+        clock_seq_low: 0x79,
+        node: [0x3D, 0x69, 0xD8, 0x47, 0x7D, 0xE4],
+    };
+
+    /// GPT partition-type GUID for Linux swap space.
+    pub const LINUX_SWAP: Guid = Guid {
+        time_low: [0x6D, 0xFD, 0x57, 0x06],
+        time_mid: [0xAB, 0xA4],
+        time_high_and_version: [0xC4, 0x43],
+        clock_seq_high_and_reserved: 0x84,
+        clock_seq_low: 0xE5,
+        node: [0x09, 0x33, 0xC8, 0x4B, 0x4F, 0x4F],
+    };
+
+    /// GPT partition-type GUID for a Linux LVM physical volume.
+    pub const LINUX_LVM: Guid = Guid {
+        time_low: [0x79, 0xD3, 0xD6, 0xE6],
+        time_mid: [0x07, 0xF5],
+        time_high_and_version: [0xC2, 0x44],
+        clock_seq_high_and_reserved: 0xA2,
+        clock_seq_low: 0x3C,
+        node: [0x23, 0x8F, 0x2A, 0x3D, 0xF9, 0x28],
+    };
+
+    /// GPT partition-type GUID for a Linux RAID member.
+    pub const LINUX_RAID: Guid = Guid {
+        time_low: [0x0F, 0x88, 0x9D, 0xA1],
+        time_mid: [0xFC, 0x05],
+        time_high_and_version: [0x3B, 0x4D],
+        clock_seq_high_and_reserved: 0xA0,
+        clock_seq_low: 0x06,
+        node: [0x74, 0x3F, 0x0F, 0x84, 0x91, 0x1E],
+    };
+
+    /// GPT partition-type GUID for a Microsoft basic data partition.
+    pub const MICROSOFT_BASIC_DATA: Guid = Guid {
+        time_low: [0xA2, 0xA0, 0xD0, 0xEB],
+        time_mid: [0xE5, 0xB9],
+        time_high_and_version: [0x33, 0x44],
+        clock_seq_high_and_reserved: 0x87,
+        clock_seq_low: 0xC0,
+        node: [0x68, 0xB6, 0xB7, 0x26, 0x99, 0xC7],
+    };
+
+    /// GPT partition-type GUID reserved by Microsoft for OS use.
+    pub const MICROSOFT_RESERVED: Guid = Guid {
+        time_low: [0x16, 0xE3, 0xC9, 0xE3],
+        time_mid: [0x5C, 0x0B],
+        time_high_and_version: [0xB8, 0x4D],
+        clock_seq_high_and_reserved: 0x81,
+        clock_seq_low: 0x7D,
+        node: [0xF9, 0x2D, 0xF0, 0x02, 0x15, 0xAE],
+    };
+
+    /// GPT partition-type GUID for the Windows Recovery Environment.
+    pub const WINDOWS_RECOVERY: Guid = Guid {
+        time_low: [0xA4, 0xBB, 0x94, 0xDE],
+        time_mid: [0xD1, 0x06],
+        time_high_and_version: [0x40, 0x4D],
+        clock_seq_high_and_reserved: 0xA1,
+        clock_seq_low: 0x6A,
+        node: [0xBF, 0xD5, 0x01, 0x79, 0xD6, 0xAC],
+    };
+
+    /// GPT partition-type GUID for an Apple HFS+ partition.
+    pub const APPLE_HFS_PLUS: Guid = Guid {
+        time_low: [0x00, 0x53, 0x46, 0x48],
+        time_mid: [0x00, 0x00],
+        time_high_and_version: [0xAA, 0x11],
+        clock_seq_high_and_reserved: 0xAA,
+        clock_seq_low: 0x11,
+        node: [0x00, 0x30, 0x65, 0x43, 0xEC, 0xAC],
+    };
+
+    /// GPT partition-type GUID for an Apple APFS partition.
+    pub const APPLE_APFS: Guid = Guid {
+        time_low: [0xEF, 0x57, 0x34, 0x7C],
+        time_mid: [0x00, 0x00],
+        time_high_and_version: [0xAA, 0x11],
+        clock_seq_high_and_reserved: 0xAA,
+        clock_seq_low: 0x11,
+        node: [0x00, 0x30, 0x65, 0x43, 0xEC, 0xAC],
+    };
+
+    /// GPT partition-type GUID for a ChromeOS kernel partition.
+    pub const CHROMEOS_KERNEL: Guid = Guid {
+        time_low: [0x5D, 0x2A, 0x3A, 0xFE],
+        time_mid: [0x32, 0x4F],
+        time_high_and_version: [0xA7, 0x41],
+        clock_seq_high_and_reserved: 0xB7,
+        clock_seq_low: 0x25,
+        node: [0xAC, 0xCC, 0x32, 0x85, 0xA3, 0x09],
+    };
+
+    /// GPT partition-type GUID for a ChromeOS root filesystem partition.
+    pub const CHROMEOS_ROOTFS: Guid = Guid {
+        time_low: [0x02, 0xE2, 0xB8, 0x3C],
+        time_mid: [0x7E, 0x3B],
+        time_high_and_version: [0xDD, 0x47],
+        clock_seq_high_and_reserved: 0x8A,
+        clock_seq_low: 0x3C,
+        node: [0x7F, 0xF2, 0xA1, 0x3C, 0xFC, 0xEC],
+    };
+
+    /// Look up the human-readable name of a well-known GPT
+    /// partition-type GUID.
+    ///
+    /// Returns `None` if `self` is not one of the constants defined on
+    /// `Guid`, such as [`Guid::EFI_SYSTEM_PARTITION`].
+    #[must_use]
+    pub const fn well_known_name(&self) -> Option<&'static str> {
+        // `Guid` does not implement `const PartialEq`, so compare the
+        // underlying bytes directly.
+        macro_rules! check {
+            ($name:expr, $guid:expr) => {
+                if self.bytes_eq($guid) {
+                    return Some($name);
+                }
+            };
+        }
+
+        check!("Unused Entry", Self::UNUSED);
+        check!("EFI System Partition", Self::EFI_SYSTEM_PARTITION);
+        check!("Legacy MBR", Self::LEGACY_MBR);
+        check!("Linux Filesystem Data", Self::LINUX_FILESYSTEM_DATA);
+        check!("Linux Swap", Self::LINUX_SWAP);
+        check!("Linux LVM", Self::LINUX_LVM);
+        check!("Linux RAID", Self::LINUX_RAID);
+        check!("Microsoft Basic Data", Self::MICROSOFT_BASIC_DATA);
+        check!("Microsoft Reserved", Self::MICROSOFT_RESERVED);
+        check!("Windows Recovery Environment", Self::WINDOWS_RECOVERY);
+        check!("Apple HFS+", Self::APPLE_HFS_PLUS);
+        check!("Apple APFS", Self::APPLE_APFS);
+        check!("ChromeOS Kernel", Self::CHROMEOS_KERNEL);
+        check!("ChromeOS Root Filesystem", Self::CHROMEOS_ROOTFS);
+
+        None
+    }
+
+    /// Const-friendly byte-wise equality, used by [`Self::well_known_name`]
+    /// since `PartialEq` cannot be called from a `const fn`.
+    const fn bytes_eq(self, other: Guid) -> bool {
+        self.time_low[0] == other.time_low[0]
+            && self.time_low[1] == other.time_low[1]
+            && self.time_low[2] == other.time_low[2]
+            && self.time_low[3] == other.time_low[3]
+            && self.time_mid[0] == other.time_mid[0]
+            && self.time_mid[1] == other.time_mid[1]
+            && self.time_high_and_version[0] == other.time_high_and_version[0]
+            && self.time_high_and_version[1] == other.time_high_and_version[1]
+            && self.clock_seq_high_and_reserved == other.clock_seq_high_and_reserved
+            && self.clock_seq_low == other.clock_seq_low
+            && self.node[0] == other.node[0]
+            && self.node[1] == other.node[1]
+            && self.node[2] == other.node[2]
+            && self.node[3] == other.node[3]
+            && self.node[4] == other.node[4]
+            && self.node[5] == other.node[5]
+    }
+}
+
+/// A [`Guid`] known to identify the type of a GPT partition.
+///
+/// This is a thin wrapper around [`Guid`] whose [`Display`] impl prints
+/// the well-known partition-type name when one is available (see
+/// [`Guid::well_known_name`]), falling back to the standard hex format
+/// otherwise.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PartitionType(pub Guid);
+
+impl PartitionType {
+    /// Create a `PartitionType` wrapping `guid`.
+    #[must_use]
+    pub const fn new(guid: Guid) -> Self {
+        Self(guid)
+    }
+
+    /// Get the underlying [`Guid`].
+    #[must_use]
+    pub const fn to_guid(self) -> Guid {
+        self.0
+    }
+}
+
+impl From<Guid> for PartitionType {
+    fn from(guid: Guid) -> Self {
+        Self(guid)
+    }
+}
+
+impl Display for PartitionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0.well_known_name() {
+            Some(name) => f.write_str(name),
+            None => Display::fmt(&self.0, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_name() {
+        assert_eq!(
+            Guid::EFI_SYSTEM_PARTITION.well_known_name(),
+            Some("EFI System Partition")
+        );
+        assert_eq!(Guid::UNUSED.well_known_name(), Some("Unused Entry"));
+    }
+
+    #[test]
+    fn test_well_known_name_none() {
+        let random = Guid {
+            time_low: [0x01, 0x02, 0x03, 0x04],
+            time_mid: [0x05, 0x06],
+            time_high_and_version: [0x07, 0x08],
+            clock_seq_high_and_reserved: 0x09,
+            clock_seq_low: 0x0A,
+            node: [0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10],
+        };
+        assert_eq!(random.well_known_name(), None);
+    }
+
+    #[test]
+    fn test_partition_type_display() {
+        assert_eq!(
+            PartitionType::new(Guid::EFI_SYSTEM_PARTITION).to_string(),
+            "EFI System Partition"
+        );
+
+        let random = Guid {
+            time_low: [0x01, 0x02, 0x03, 0x04],
+            time_mid: [0x05, 0x06],
+            time_high_and_version: [0x07, 0x08],
+            clock_seq_high_and_reserved: 0x09,
+            clock_seq_low: 0x0A,
+            node: [0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10],
+        };
+        assert_eq!(
+            PartitionType::new(random).to_string(),
+            random.to_string()
+        );
+    }
+}